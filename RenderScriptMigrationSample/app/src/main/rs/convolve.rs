@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2021 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma version(1)
+#pragma rs java_package_name(com.android.example.rsmigration)
+#pragma rs_fp_relaxed
+
+#define MAX_COEFF_SIZE 25
+
+int32_t gWidth;
+int32_t gHeight;
+rs_allocation gInputAlloc;
+
+int32_t gSize;
+float gCoeffs[MAX_COEFF_SIZE];
+float gDivisor;
+float gBias;
+
+uchar4 RS_KERNEL root(uint32_t x, uint32_t y) {
+    float4 sum = 0;
+    int half = gSize / 2;
+    int k = 0;
+    for (int j = -half; j <= half; j++) {
+        int validY = clamp((int)y + j, (int)0, (int)(gHeight - 1));
+        for (int i = -half; i <= half; i++) {
+            int validX = clamp((int)x + i, (int)0, (int)(gWidth - 1));
+            uchar4 in = rsGetElementAt_uchar4(gInputAlloc, validX, validY);
+            sum += convert_float4(in) * gCoeffs[k++];
+        }
+    }
+
+    // A zero-sum coefficient matrix (e.g. an edge-detect kernel) legitimately
+    // calls for gDivisor == 0; fall back to the unscaled sum instead of
+    // dividing by zero.
+    float4 out = (gDivisor != 0.f ? sum / gDivisor : sum) + gBias;
+    out.rgb = clamp(out.rgb, 0.f, 255.f);
+    out.a = 0xff;
+    return convert_uchar4(out);
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (C) 2021 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma version(1)
+#pragma rs java_package_name(com.android.example.rsmigration)
+#pragma rs_fp_relaxed
+
+int32_t gWidth;
+int32_t gHeight;
+rs_allocation gInputAlloc;
+
+float gStrength;
+bool gRelaxed;
+
+static uchar4 sampleBilinear(float u, float v) {
+    float x = u * (gWidth - 1);
+    float y = v * (gHeight - 1);
+    int x0 = clamp((int)x, (int)0, (int)(gWidth - 1));
+    int y0 = clamp((int)y, (int)0, (int)(gHeight - 1));
+    int x1 = clamp(x0 + 1, (int)0, (int)(gWidth - 1));
+    int y1 = clamp(y0 + 1, (int)0, (int)(gHeight - 1));
+    float fx = x - x0;
+    float fy = y - y0;
+
+    float4 c00 = convert_float4(rsGetElementAt_uchar4(gInputAlloc, x0, y0));
+    float4 c10 = convert_float4(rsGetElementAt_uchar4(gInputAlloc, x1, y0));
+    float4 c01 = convert_float4(rsGetElementAt_uchar4(gInputAlloc, x0, y1));
+    float4 c11 = convert_float4(rsGetElementAt_uchar4(gInputAlloc, x1, y1));
+
+    float4 top = c00 * (1.f - fx) + c10 * fx;
+    float4 bottom = c01 * (1.f - fx) + c11 * fx;
+    return convert_uchar4(clamp(top * (1.f - fy) + bottom * fy, 0.f, 255.f));
+}
+
+uchar4 RS_KERNEL root(uint32_t x, uint32_t y) {
+    float halfDim = max(gWidth, gHeight) * 0.5f;
+    float2 coord;
+    coord.x = (x - gWidth * 0.5f) / halfDim;
+    coord.y = (y - gHeight * 0.5f) / halfDim;
+
+    float r = length(coord);
+    float factor;
+    if (gRelaxed) {
+        // Cheap polynomial approximation of the trig warp below.
+        factor = 1.f + r * r * gStrength;
+    } else if (gStrength != 0.f && r > 0.f) {
+        float bound = 2.f * sin(atan(r * gStrength)) / (2.f * sin(atan(gStrength)));
+        factor = bound / r;
+    } else {
+        // gStrength == 0 (or image center) means no distortion; the trig
+        // ratio above would otherwise divide 0 by 0.
+        factor = 1.f;
+    }
+
+    float2 warped = coord * factor;
+    float u = (warped.x * halfDim + gWidth * 0.5f) / max(gWidth - 1, 1);
+    float v = (warped.y * halfDim + gHeight * 0.5f) / max(gHeight - 1, 1);
+    u = clamp(u, 0.f, 1.f);
+    v = clamp(v, 0.f, 1.f);
+
+    return sampleBilinear(u, v);
+}
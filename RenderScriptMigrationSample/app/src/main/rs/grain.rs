@@ -0,0 +1,80 @@
+/*
+ * Copyright (C) 2021 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma version(1)
+#pragma rs java_package_name(com.android.example.rsmigration)
+#pragma rs_fp_relaxed
+
+#define MAX_KERNEL_SIZE 51
+
+int32_t gWidth;
+int32_t gHeight;
+rs_allocation gScratch1;
+rs_allocation gScratch2;
+
+int32_t gRadius;
+float gKernel[MAX_KERNEL_SIZE];
+
+int32_t gSeed;
+float gGrainStrength;
+rs_allocation gInputAlloc;
+
+static uint hash(uint x) {
+    x ^= x >> 16;
+    x *= 0x7feb352dU;
+    x ^= x >> 15;
+    x *= 0x846ca68bU;
+    x ^= x >> 16;
+    return x;
+}
+
+float RS_KERNEL genRand(uint32_t x, uint32_t y) {
+    uint h = hash(x * 1973u + y * 9277u + gSeed * 26699u);
+    return (h % 2000001) / 1000000.f - 1.f;
+}
+
+float RS_KERNEL horizontal(uint32_t x, uint32_t y) {
+    float blurred = 0;
+    int i = 0;
+    for (int r = -gRadius; r <= gRadius; r++) {
+        int validX = clamp((int)x + r, (int)0, (int)(gWidth - 1));
+        float n = rsGetElementAt_float(gScratch1, validX, y);
+        blurred += n * gKernel[i++];
+    }
+    return blurred;
+}
+
+float RS_KERNEL vertical(uint32_t x, uint32_t y) {
+    float blurred = 0;
+    int i = 0;
+    for (int r = -gRadius; r <= gRadius; r++) {
+        int validY = clamp((int)y + r, (int)0, (int)(gHeight - 1));
+        float n = rsGetElementAt_float(gScratch2, x, validY);
+        blurred += n * gKernel[i++];
+    }
+    return blurred;
+}
+
+uchar4 RS_KERNEL blend(float in, uint32_t x, uint32_t y) {
+    uchar4 original = rsGetElementAt_uchar4(gInputAlloc, x, y);
+
+    float4 color = convert_float4(original);
+    color.rgb = clamp(color.rgb + in * gGrainStrength, 0.f, 255.f);
+
+    uchar4 out = convert_uchar4(color);
+    out.a = original.a;
+    return out;
+}
@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2021 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma version(1)
+#pragma rs java_package_name(com.android.example.rsmigration)
+#pragma rs_fp_relaxed
+
+// Rec.601 luminance weights by default; callers can switch to Rec.709 or a
+// flat average by setting gWeights at runtime.
+float3 gWeights = {0.299f, 0.587f, 0.114f};
+
+uchar4 RS_KERNEL root(uchar4 in) {
+    float4 color = convert_float4(in);
+    float y = dot(color.rgb, gWeights);
+    uchar4 out;
+    out.rgb = convert_uchar3(clamp((float3)y, 0.f, 255.f));
+    out.a = in.a;
+    return out;
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (C) 2021 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma version(1)
+#pragma rs java_package_name(com.android.example.rsmigration)
+#pragma rs_fp_relaxed
+
+static rs_matrix3x3 LevelsMatrix;
+static float3 LevelsOffset;
+
+void init() {
+    rsMatrixLoadIdentity(&LevelsMatrix);
+    LevelsOffset = 0.f;
+}
+
+void setParams(float brightness, float contrast, float saturation) {
+    // Rec.601 luminance weights.
+    const float kLumaR = 0.299f;
+    const float kLumaG = 0.587f;
+    const float kLumaB = 0.114f;
+
+    // Interpolate each channel between the Rec.601 luminance and the
+    // original channel by `saturation`, then fold in the contrast scale
+    // so a single matrix multiply covers all three adjustments.
+    rsMatrixSet(&LevelsMatrix, 0, 0, contrast * ((1.f - saturation) * kLumaR + saturation));
+    rsMatrixSet(&LevelsMatrix, 1, 0, contrast * (1.f - saturation) * kLumaG);
+    rsMatrixSet(&LevelsMatrix, 2, 0, contrast * (1.f - saturation) * kLumaB);
+
+    rsMatrixSet(&LevelsMatrix, 0, 1, contrast * (1.f - saturation) * kLumaR);
+    rsMatrixSet(&LevelsMatrix, 1, 1, contrast * ((1.f - saturation) * kLumaG + saturation));
+    rsMatrixSet(&LevelsMatrix, 2, 1, contrast * (1.f - saturation) * kLumaB);
+
+    rsMatrixSet(&LevelsMatrix, 0, 2, contrast * (1.f - saturation) * kLumaR);
+    rsMatrixSet(&LevelsMatrix, 1, 2, contrast * (1.f - saturation) * kLumaG);
+    rsMatrixSet(&LevelsMatrix, 2, 2, contrast * ((1.f - saturation) * kLumaB + saturation));
+
+    LevelsOffset = 128.f * (1.f - contrast) + brightness;
+}
+
+uchar4 RS_KERNEL root(uchar4 in) {
+    float4 color = convert_float4(in);
+    color.rgb = rsMatrixMultiply(&LevelsMatrix, color.rgb) + LevelsOffset;
+    color.rgb = clamp(color.rgb, 0.f, 255.f);
+    return convert_uchar4(color);
+}